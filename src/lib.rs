@@ -111,6 +111,144 @@
 #![deny(unsafe_code, missing_docs)]
 #![no_std]
 
+/// Reverse the order of the bits of a byte.
+///
+/// This is used to implement the `RefIn`/`RefOut` parameters of the standard
+/// (Rocksoft) CRC parameter model.
+pub const fn reflect_u8(byte: u8) -> u8 {
+    let b = ((byte & 0xF0) >> 4) | ((byte & 0x0F) << 4);
+    let b = ((b & 0xCC) >> 2) | ((b & 0x33) << 2);
+    ((b & 0xAA) >> 1) | ((b & 0x55) << 1)
+}
+
+/// Reverse the order of the bits of a 16-bit value.
+///
+/// This is the `u16` counterpart of [`reflect_u8`] used for the wider CRC-16 algorithms.
+pub const fn reflect_u16(value: u16) -> u16 {
+    let v = ((value & 0xFF00) >> 8) | ((value & 0x00FF) << 8);
+    let v = ((v & 0xF0F0) >> 4) | ((v & 0x0F0F) << 4);
+    let v = ((v & 0xCCCC) >> 2) | ((v & 0x3333) << 2);
+    ((v & 0xAAAA) >> 1) | ((v & 0x5555) << 1)
+}
+
+/// Reverse the order of the bits of a 32-bit value.
+///
+/// This is the `u32` counterpart of [`reflect_u8`] used for the wider CRC-32 algorithms.
+pub const fn reflect_u32(value: u32) -> u32 {
+    let v = ((value & 0xFFFF0000) >> 16) | ((value & 0x0000FFFF) << 16);
+    let v = ((v & 0xFF00FF00) >> 8) | ((v & 0x00FF00FF) << 8);
+    let v = ((v & 0xF0F0F0F0) >> 4) | ((v & 0x0F0F0F0F) << 4);
+    let v = ((v & 0xCCCCCCCC) >> 2) | ((v & 0x33333333) << 2);
+    ((v & 0xAAAAAAAA) >> 1) | ((v & 0x55555555) << 1)
+}
+
+/// Compute the 256-entry CRC-8 lookup table for the given polynomial at compile time.
+///
+/// This is the `const` counterpart of
+/// [`build_rs_lookup_table_file_generation`](macro.build_rs_lookup_table_file_generation.html):
+/// it materializes the same table as a plain `const` without a build script, so a
+/// `LOOKUP_TABLE` for use with [`crc8_lookup_table`](macro.crc8_lookup_table.html) or
+/// [`crc8_hasher_lookup_table`](macro.crc8_hasher_lookup_table.html) can be defined with zero
+/// setup:
+/// ```rust
+/// use embedded_crc_macros::{crc8_lookup_table, generate_crc8_lookup_table};
+///
+/// const LOOKUP_TABLE: [u8; 256] = generate_crc8_lookup_table(7);
+/// crc8_lookup_table!(smbus_pec, 0, "SMBus Packet Error Code");
+/// ```
+///
+/// As in the lookup-based macros, the `RefIn`/`RefOut`/`XorOut` parameters are applied by the
+/// generated function, so the table itself only depends on the polynomial.
+pub const fn generate_crc8_lookup_table(poly: u8) -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if (crc & 0x80) != 0 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Compute the 256-entry CRC-16 lookup table for the given polynomial at compile time.
+///
+/// This is the `u16` counterpart of [`generate_crc8_lookup_table`]: it materializes the
+/// most-significant-bit-first table consumed by
+/// [`crc16_lookup_table`](macro.crc16_lookup_table.html) as a plain `const`, so no build
+/// script is needed:
+/// ```rust
+/// use embedded_crc_macros::{crc16_lookup_table, generate_crc16_lookup_table};
+///
+/// const LOOKUP_TABLE: [u16; 256] = generate_crc16_lookup_table(0x1021);
+/// crc16_lookup_table!(ccitt_false, 0xFFFF, "CRC-16/CCITT-FALSE");
+/// ```
+///
+/// As in the 8-bit case, the `RefIn`/`RefOut`/`XorOut` parameters are applied by the generated
+/// function, so the table itself only depends on the polynomial.
+pub const fn generate_crc16_lookup_table(poly: u16) -> [u16; 256] {
+    let mut table = [0u16; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if (crc & 0x8000) != 0 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Compute the 256-entry CRC-32 lookup table for the given polynomial at compile time.
+///
+/// This is the `u32` counterpart of [`generate_crc8_lookup_table`]: it materializes the
+/// most-significant-bit-first table consumed by
+/// [`crc32_lookup_table`](macro.crc32_lookup_table.html) as a plain `const`, so no build
+/// script is needed:
+/// ```rust
+/// use embedded_crc_macros::{crc32_lookup_table, generate_crc32_lookup_table};
+///
+/// const LOOKUP_TABLE: [u32; 256] = generate_crc32_lookup_table(0x04C1_1DB7);
+/// crc32_lookup_table!(iso_hdlc, 0xFFFF_FFFF, true, true, 0xFFFF_FFFF, "CRC-32/ISO-HDLC");
+/// ```
+///
+/// As in the 8-bit case, the `RefIn`/`RefOut`/`XorOut` parameters are applied by the generated
+/// function, so the table itself only depends on the polynomial.
+pub const fn generate_crc32_lookup_table(poly: u32) -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u32) << 24;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if (crc & 0x8000_0000) != 0 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
 /// Define public function implementing the CRC-8 algorithm for the given polynomial and initial value.
 ///
 /// A function name and some documentation for it must be provided. For example:
@@ -118,13 +256,21 @@
 /// use embedded_crc_macros::crc8;
 /// crc8!(smbus_pec, 7 /* x^8+x^2+x+1 */, 0, "SMBus Packet Error Code");
 /// ```
+///
+/// The full set of standard (Rocksoft) parameters can be provided as well to
+/// express the reflected variants that apply a final XOR, such as CRC-8/MAXIM:
+/// ```rust
+/// use embedded_crc_macros::crc8;
+/// crc8!(maxim, 0x31, 0, true, true, 0, "CRC-8/MAXIM");
+/// ```
 #[macro_export]
 macro_rules! crc8 {
-    ($function_name:ident, $poly:expr, $initial_value:expr, $doc:expr) => {
+    ($function_name:ident, $poly:expr, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
         #[doc=$doc]
         pub fn $function_name(data: &[u8]) -> u8 {
-            let mut crc = $initial_value;
+            let mut crc: u8 = $initial_value;
             for byte in data {
+                let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
                 crc ^= byte;
                 for _ in 0..8 {
                     crc = if (crc & (1 << 7)) != 0 {
@@ -134,9 +280,15 @@ macro_rules! crc8 {
                     };
                 }
             }
-            crc
+            if $refout {
+                crc = $crate::reflect_u8(crc);
+            }
+            crc ^ $xorout
         }
     };
+    ($function_name:ident, $poly:expr, $initial_value:expr, $doc:expr) => {
+        $crate::crc8!($function_name, $poly, $initial_value, false, false, 0, $doc);
+    };
 }
 
 /// Define public function implementing the CRC-8 algorithm for the given polynomial
@@ -159,16 +311,23 @@ macro_rules! crc8 {
 /// ```
 #[macro_export]
 macro_rules! crc8_lookup_table {
-    ($function_name:ident, $initial_value:expr, $doc:expr) => {
+    ($function_name:ident, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
         #[doc=$doc]
         pub fn $function_name(data: &[u8]) -> u8 {
-            let mut crc = $initial_value;
+            let mut crc: u8 = $initial_value;
             for byte in data {
-                crc = LOOKUP_TABLE[(crc ^ *byte) as usize];
+                let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                crc = LOOKUP_TABLE[(crc ^ byte) as usize];
             }
-            crc
+            if $refout {
+                crc = $crate::reflect_u8(crc);
+            }
+            crc ^ $xorout
         }
     };
+    ($function_name:ident, $initial_value:expr, $doc:expr) => {
+        $crate::crc8_lookup_table!($function_name, $initial_value, false, false, 0, $doc);
+    };
 }
 
 /// Define structure implementing the CRC-8 algorithm for the given polynomial and initial value
@@ -189,8 +348,9 @@ macro_rules! crc8_lookup_table {
 /// ```
 #[macro_export]
 macro_rules! crc8_hasher {
-    ($struct_name:ident, $poly:expr, $initial_value:expr, $doc:expr) => {
+    ($struct_name:ident, $poly:expr, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
         #[doc=$doc]
+        #[derive(Clone, Copy, PartialEq, Eq)]
         struct $struct_name {
             crc: u8,
         }
@@ -203,10 +363,17 @@ macro_rules! crc8_hasher {
             }
         }
 
+        impl Default for $struct_name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
         impl core::hash::Hasher for $struct_name {
             #[inline]
             fn write(&mut self, bytes: &[u8]) {
                 for byte in bytes {
+                    let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
                     self.crc ^= byte;
                     for _ in 0..8 {
                         self.crc = if (self.crc & (1 << 7)) != 0 {
@@ -220,10 +387,34 @@ macro_rules! crc8_hasher {
 
             #[inline]
             fn finish(&self) -> u64 {
-                self.crc as u64
+                let crc = if $refout {
+                    $crate::reflect_u8(self.crc)
+                } else {
+                    self.crc
+                };
+                (crc ^ $xorout) as u64
+            }
+        }
+
+        impl core::fmt::Debug for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                // The running internal register, not the finalized checksum
+                // (`finish` applies RefOut/XorOut); handy for inspecting framing.
+                write!(f, "0x{:02X}", self.crc)
+            }
+        }
+
+        impl core::fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                // The running internal register, not the finalized checksum
+                // (`finish` applies RefOut/XorOut); handy for inspecting framing.
+                write!(f, "0x{:02X}", self.crc)
             }
         }
     };
+    ($struct_name:ident, $poly:expr, $initial_value:expr, $doc:expr) => {
+        $crate::crc8_hasher!($struct_name, $poly, $initial_value, false, false, 0, $doc);
+    };
 }
 
 /// Define structure implementing the CRC-8 algorithm as a `core::hash::Hasher`
@@ -254,8 +445,9 @@ macro_rules! crc8_hasher {
 /// ```
 #[macro_export]
 macro_rules! crc8_hasher_lookup_table {
-    ($name:ident, $initial_value:expr, $doc:expr) => {
+    ($name:ident, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
         #[doc=$doc]
+        #[derive(Clone, Copy, PartialEq, Eq)]
         struct $name {
             crc: u8,
         }
@@ -269,20 +461,444 @@ macro_rules! crc8_hasher_lookup_table {
             }
         }
 
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
         impl core::hash::Hasher for $name {
             #[inline]
             fn write(&mut self, bytes: &[u8]) {
                 for byte in bytes {
-                    self.crc = LOOKUP_TABLE[(self.crc ^ *byte) as usize];
+                    let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                    self.crc = LOOKUP_TABLE[(self.crc ^ byte) as usize];
+                }
+            }
+
+            #[inline]
+            fn finish(&self) -> u64 {
+                let crc = if $refout {
+                    $crate::reflect_u8(self.crc)
+                } else {
+                    self.crc
+                };
+                (crc ^ $xorout) as u64
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                // The running internal register, not the finalized checksum
+                // (`finish` applies RefOut/XorOut); handy for inspecting framing.
+                write!(f, "0x{:02X}", self.crc)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                // The running internal register, not the finalized checksum
+                // (`finish` applies RefOut/XorOut); handy for inspecting framing.
+                write!(f, "0x{:02X}", self.crc)
+            }
+        }
+    };
+    ($name:ident, $initial_value:expr, $doc:expr) => {
+        $crate::crc8_hasher_lookup_table!($name, $initial_value, false, false, 0, $doc);
+    };
+}
+
+/// Define public function implementing a well-known standard CRC-8 algorithm by name.
+///
+/// This maps a catalog identifier to the full (Rocksoft) parameter tuple and delegates to
+/// [`crc8`](macro.crc8.html), so the easy-to-get-wrong parameters do not need to be looked up
+/// and everything is still resolved at compile time.
+///
+/// A function name and the catalog identifier must be provided. For example:
+/// ```rust
+/// use embedded_crc_macros::crc8_preset;
+/// crc8_preset!(maxim, CRC_8_MAXIM);
+/// ```
+///
+/// The following algorithms are available: `CRC_8_SMBUS`, `CRC_8_MAXIM`, `CRC_8_DARC`,
+/// `CRC_8_ROHC` and `CRC_8_WCDMA`.
+#[macro_export]
+macro_rules! crc8_preset {
+    ($function_name:ident, CRC_8_SMBUS) => {
+        $crate::crc8!($function_name, 0x07, 0x00, false, false, 0x00, "CRC-8/SMBUS");
+    };
+    ($function_name:ident, CRC_8_MAXIM) => {
+        $crate::crc8!($function_name, 0x31, 0x00, true, true, 0x00, "CRC-8/MAXIM");
+    };
+    ($function_name:ident, CRC_8_DARC) => {
+        $crate::crc8!($function_name, 0x39, 0x00, true, true, 0x00, "CRC-8/DARC");
+    };
+    ($function_name:ident, CRC_8_ROHC) => {
+        $crate::crc8!($function_name, 0x07, 0xFF, true, true, 0x00, "CRC-8/ROHC");
+    };
+    ($function_name:ident, CRC_8_WCDMA) => {
+        $crate::crc8!($function_name, 0x9B, 0x00, true, true, 0x00, "CRC-8/WCDMA");
+    };
+}
+
+/// Define public function implementing a CRC algorithm of a width of 8 bits or less.
+///
+/// In addition to the polynomial and initial value, the width in bits `w` (with `1 <= w <= 8`)
+/// must be provided. The polynomial and register are left-aligned to the top `w` bits of a `u8`
+/// while accumulating and the result is returned masked to `w` bits, so small CRCs such as
+/// CRC-7/MMC (used by SD card stacks) yield their canonical value.
+///
+/// A function name and some documentation for it must be provided. For example:
+/// ```rust
+/// use embedded_crc_macros::crc8_width;
+/// crc8_width!(crc7_mmc, 7, 0x09, 0, "CRC-7/MMC");
+/// ```
+///
+/// The full set of standard (Rocksoft) parameters can be provided as well, e.g. CRC-5/USB:
+/// ```rust
+/// use embedded_crc_macros::crc8_width;
+/// crc8_width!(crc5_usb, 5, 0x05, 0x1F, true, true, 0x1F, "CRC-5/USB");
+/// ```
+#[macro_export]
+macro_rules! crc8_width {
+    ($function_name:ident, $bits:expr, $poly:expr, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
+        #[doc=$doc]
+        pub fn $function_name(data: &[u8]) -> u8 {
+            const SHIFT: u8 = 8 - $bits;
+            const MASK: u8 = (((1u16 << $bits) - 1) as u8);
+            let mut crc: u8 = ($initial_value as u8) << SHIFT;
+            for byte in data {
+                let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                crc ^= byte;
+                for _ in 0..8 {
+                    crc = if (crc & 0x80) != 0 {
+                        (crc << 1) ^ (($poly as u8) << SHIFT)
+                    } else {
+                        crc << 1
+                    };
+                }
+            }
+            let crc = crc >> SHIFT;
+            let crc = if $refout {
+                $crate::reflect_u8(crc) >> SHIFT
+            } else {
+                crc
+            };
+            (crc ^ $xorout) & MASK
+        }
+    };
+    ($function_name:ident, $bits:expr, $poly:expr, $initial_value:expr, $doc:expr) => {
+        $crate::crc8_width!($function_name, $bits, $poly, $initial_value, false, false, 0, $doc);
+    };
+}
+
+/// Define public function implementing the CRC-16 algorithm for the given polynomial and initial value.
+///
+/// A function name and some documentation for it must be provided. For example:
+/// ```rust
+/// use embedded_crc_macros::crc16;
+/// crc16!(ccitt_false, 0x1021, 0xFFFF, "CRC-16/CCITT-FALSE");
+/// ```
+///
+/// The full set of standard (Rocksoft) parameters can be provided to express the
+/// reflected variants such as CRC-16/MODBUS:
+/// ```rust
+/// use embedded_crc_macros::crc16;
+/// crc16!(modbus, 0x8005, 0xFFFF, true, true, 0, "CRC-16/MODBUS");
+/// ```
+#[macro_export]
+macro_rules! crc16 {
+    ($function_name:ident, $poly:expr, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
+        #[doc=$doc]
+        pub fn $function_name(data: &[u8]) -> u16 {
+            let mut crc: u16 = $initial_value;
+            for byte in data {
+                let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                crc ^= (byte as u16) << 8;
+                for _ in 0..8 {
+                    crc = if (crc & (1 << 15)) != 0 {
+                        (crc << 1) ^ $poly
+                    } else {
+                        crc << 1
+                    };
+                }
+            }
+            if $refout {
+                crc = $crate::reflect_u16(crc);
+            }
+            crc ^ $xorout
+        }
+    };
+    ($function_name:ident, $poly:expr, $initial_value:expr, $doc:expr) => {
+        $crate::crc16!($function_name, $poly, $initial_value, false, false, 0, $doc);
+    };
+}
+
+/// Define public function implementing the CRC-16 algorithm for the given polynomial
+/// and initial value using a lookup table.
+///
+/// This implementation is much faster at the cost of some space.
+/// A lookup table must be defined in the same environment as `LOOKUP_TABLE` of type `[u16; 256]`.
+/// ```rust
+/// use embedded_crc_macros::{crc16_lookup_table, generate_crc16_lookup_table};
+/// const LOOKUP_TABLE: [u16; 256] = generate_crc16_lookup_table(0x1021);
+/// crc16_lookup_table!(ccitt_false, 0xFFFF, "CRC-16/CCITT-FALSE");
+/// ```
+#[macro_export]
+macro_rules! crc16_lookup_table {
+    ($function_name:ident, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
+        #[doc=$doc]
+        pub fn $function_name(data: &[u8]) -> u16 {
+            let mut crc: u16 = $initial_value;
+            for byte in data {
+                let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                crc = (crc << 8) ^ LOOKUP_TABLE[(((crc >> 8) as u8) ^ byte) as usize];
+            }
+            if $refout {
+                crc = $crate::reflect_u16(crc);
+            }
+            crc ^ $xorout
+        }
+    };
+    ($function_name:ident, $initial_value:expr, $doc:expr) => {
+        $crate::crc16_lookup_table!($function_name, $initial_value, false, false, 0, $doc);
+    };
+}
+
+/// Define structure implementing the CRC-16 algorithm for the given polynomial and initial value
+/// as a `core::hash::Hasher` trait implementation.
+///
+/// A struct name and some documentation for it must be provided. For example:
+/// ```rust
+/// use core::hash::Hasher;
+/// use embedded_crc_macros::crc16_hasher;
+///
+/// crc16_hasher!(CcittFalse, 0x1021, 0xFFFF, "CRC-16/CCITT-FALSE");
+///
+/// let mut hasher = CcittFalse::new();
+/// hasher.write(&[0xAB, 0xCD]);
+/// let checksum = hasher.finish();
+/// ```
+#[macro_export]
+macro_rules! crc16_hasher {
+    ($struct_name:ident, $poly:expr, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
+        #[doc=$doc]
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        struct $struct_name {
+            crc: u16,
+        }
+
+        impl $struct_name {
+            pub fn new() -> Self {
+                $struct_name {
+                    crc: $initial_value,
+                }
+            }
+        }
+
+        impl Default for $struct_name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl core::hash::Hasher for $struct_name {
+            #[inline]
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                    self.crc ^= (byte as u16) << 8;
+                    for _ in 0..8 {
+                        self.crc = if (self.crc & (1 << 15)) != 0 {
+                            (self.crc << 1) ^ $poly
+                        } else {
+                            self.crc << 1
+                        };
+                    }
+                }
+            }
+
+            #[inline]
+            fn finish(&self) -> u64 {
+                let crc = if $refout {
+                    $crate::reflect_u16(self.crc)
+                } else {
+                    self.crc
+                };
+                (crc ^ $xorout) as u64
+            }
+        }
+
+        impl core::fmt::Debug for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                // The running internal register, not the finalized checksum
+                // (`finish` applies RefOut/XorOut); handy for inspecting framing.
+                write!(f, "0x{:04X}", self.crc)
+            }
+        }
+
+        impl core::fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                // The running internal register, not the finalized checksum
+                // (`finish` applies RefOut/XorOut); handy for inspecting framing.
+                write!(f, "0x{:04X}", self.crc)
+            }
+        }
+    };
+    ($struct_name:ident, $poly:expr, $initial_value:expr, $doc:expr) => {
+        $crate::crc16_hasher!($struct_name, $poly, $initial_value, false, false, 0, $doc);
+    };
+}
+
+/// Define public function implementing the CRC-32 algorithm for the given polynomial and initial value.
+///
+/// A function name and some documentation for it must be provided. For example:
+/// ```rust
+/// use embedded_crc_macros::crc32;
+/// crc32!(iso_hdlc, 0x04C1_1DB7, 0xFFFF_FFFF, true, true, 0xFFFF_FFFF, "CRC-32/ISO-HDLC");
+/// ```
+#[macro_export]
+macro_rules! crc32 {
+    ($function_name:ident, $poly:expr, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
+        #[doc=$doc]
+        pub fn $function_name(data: &[u8]) -> u32 {
+            let mut crc: u32 = $initial_value;
+            for byte in data {
+                let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                crc ^= (byte as u32) << 24;
+                for _ in 0..8 {
+                    crc = if (crc & (1 << 31)) != 0 {
+                        (crc << 1) ^ $poly
+                    } else {
+                        crc << 1
+                    };
+                }
+            }
+            if $refout {
+                crc = $crate::reflect_u32(crc);
+            }
+            crc ^ $xorout
+        }
+    };
+    ($function_name:ident, $poly:expr, $initial_value:expr, $doc:expr) => {
+        $crate::crc32!($function_name, $poly, $initial_value, false, false, 0, $doc);
+    };
+}
+
+/// Define public function implementing the CRC-32 algorithm for the given polynomial
+/// and initial value using a lookup table.
+///
+/// This implementation is much faster at the cost of some space.
+/// A lookup table must be defined in the same environment as `LOOKUP_TABLE` of type `[u32; 256]`.
+/// ```rust
+/// use embedded_crc_macros::{crc32_lookup_table, generate_crc32_lookup_table};
+/// const LOOKUP_TABLE: [u32; 256] = generate_crc32_lookup_table(0x04C1_1DB7);
+/// crc32_lookup_table!(iso_hdlc, 0xFFFF_FFFF, true, true, 0xFFFF_FFFF, "CRC-32/ISO-HDLC");
+/// ```
+#[macro_export]
+macro_rules! crc32_lookup_table {
+    ($function_name:ident, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
+        #[doc=$doc]
+        pub fn $function_name(data: &[u8]) -> u32 {
+            let mut crc: u32 = $initial_value;
+            for byte in data {
+                let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                crc = (crc << 8) ^ LOOKUP_TABLE[(((crc >> 24) as u8) ^ byte) as usize];
+            }
+            if $refout {
+                crc = $crate::reflect_u32(crc);
+            }
+            crc ^ $xorout
+        }
+    };
+    ($function_name:ident, $initial_value:expr, $doc:expr) => {
+        $crate::crc32_lookup_table!($function_name, $initial_value, false, false, 0, $doc);
+    };
+}
+
+/// Define structure implementing the CRC-32 algorithm for the given polynomial and initial value
+/// as a `core::hash::Hasher` trait implementation.
+///
+/// A struct name and some documentation for it must be provided. For example:
+/// ```rust
+/// use core::hash::Hasher;
+/// use embedded_crc_macros::crc32_hasher;
+///
+/// crc32_hasher!(IsoHdlc, 0x04C1_1DB7, 0xFFFF_FFFF, true, true, 0xFFFF_FFFF, "CRC-32/ISO-HDLC");
+///
+/// let mut hasher = IsoHdlc::new();
+/// hasher.write(&[0xAB, 0xCD]);
+/// let checksum = hasher.finish();
+/// ```
+#[macro_export]
+macro_rules! crc32_hasher {
+    ($struct_name:ident, $poly:expr, $initial_value:expr, $refin:expr, $refout:expr, $xorout:expr, $doc:expr) => {
+        #[doc=$doc]
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        struct $struct_name {
+            crc: u32,
+        }
+
+        impl $struct_name {
+            pub fn new() -> Self {
+                $struct_name {
+                    crc: $initial_value,
+                }
+            }
+        }
+
+        impl Default for $struct_name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl core::hash::Hasher for $struct_name {
+            #[inline]
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    let byte = if $refin { $crate::reflect_u8(*byte) } else { *byte };
+                    self.crc ^= (byte as u32) << 24;
+                    for _ in 0..8 {
+                        self.crc = if (self.crc & (1 << 31)) != 0 {
+                            (self.crc << 1) ^ $poly
+                        } else {
+                            self.crc << 1
+                        };
+                    }
                 }
             }
 
             #[inline]
             fn finish(&self) -> u64 {
-                self.crc as u64
+                let crc = if $refout {
+                    $crate::reflect_u32(self.crc)
+                } else {
+                    self.crc
+                };
+                (crc ^ $xorout) as u64
+            }
+        }
+
+        impl core::fmt::Debug for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                // The running internal register, not the finalized checksum
+                // (`finish` applies RefOut/XorOut); handy for inspecting framing.
+                write!(f, "0x{:08X}", self.crc)
+            }
+        }
+
+        impl core::fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                // The running internal register, not the finalized checksum
+                // (`finish` applies RefOut/XorOut); handy for inspecting framing.
+                write!(f, "0x{:08X}", self.crc)
             }
         }
     };
+    ($struct_name:ident, $poly:expr, $initial_value:expr, $doc:expr) => {
+        $crate::crc32_hasher!($struct_name, $poly, $initial_value, false, false, 0, $doc);
+    };
 }
 
 /// Code generation macro for use in `build.rs` files.
@@ -305,7 +921,7 @@ macro_rules! crc8_hasher_lookup_table {
 /// ```
 #[macro_export]
 macro_rules! build_rs_lookup_table_file_generation {
-    ($function_name:ident, $checksum_function:ident, $lookup_table_file:expr, $t:ty, $size:expr) => {
+    ($function_name:ident, $checksum_function:ident, $refin:expr, $refout:expr, $xorout:expr, $lookup_table_file:expr, $t:ty, $size:expr) => {
         fn $function_name() -> std::io::Result<()> {
             use std::io::prelude::*;
             let out_path = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
@@ -325,7 +941,18 @@ macro_rules! build_rs_lookup_table_file_generation {
                 if i % 16 == 0 {
                     file.write_all(b"    ")?;
                 }
-                file.write_all(format!("0x{:x}, ", $checksum_function(&[i as $t])).as_bytes())?;
+                // The table holds the plain remainder for each index so that the
+                // reflection is applied by the generated lookup function, not baked
+                // into the table. Undo the `refin`/`refout`/`xorout` that the
+                // reflection-aware checksum function applies to a single byte.
+                let index = if $refin {
+                    $crate::reflect_u8(i as u8) as $t
+                } else {
+                    i as $t
+                };
+                let entry = $checksum_function(&[index]) ^ $xorout;
+                let entry = if $refout { $crate::reflect_u8(entry) } else { entry };
+                file.write_all(format!("0x{:x}, ", entry).as_bytes())?;
                 if i > 0 && (i + 1) % 16 == 0 {
                     file.write_all(b"\n")?;
                 }
@@ -333,4 +960,16 @@ macro_rules! build_rs_lookup_table_file_generation {
             file.write_all(b"];\n")
         }
     };
+    ($function_name:ident, $checksum_function:ident, $lookup_table_file:expr, $t:ty, $size:expr) => {
+        $crate::build_rs_lookup_table_file_generation!(
+            $function_name,
+            $checksum_function,
+            false,
+            false,
+            0,
+            $lookup_table_file,
+            $t,
+            $size
+        );
+    };
 }
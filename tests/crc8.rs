@@ -3,6 +3,7 @@ use base::SMBUS_PEC_LOOKUP_TABLE as LOOKUP_TABLE;
 use embedded_crc_macros::crc8;
 
 crc8!(smbus_pec, 7, 0, "SMBus Packet Error Code");
+crc8!(maxim, 0x31, 0, true, true, 0, "CRC-8/MAXIM");
 
 #[test]
 fn check_pec_table() {
@@ -33,3 +34,8 @@ fn check_pec_array() {
         233
     );
 }
+
+#[test]
+fn check_maxim_reflected() {
+    assert_eq!(maxim(b"123456789"), 0xA1);
+}
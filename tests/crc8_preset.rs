@@ -0,0 +1,18 @@
+use embedded_crc_macros::crc8_preset;
+
+crc8_preset!(smbus, CRC_8_SMBUS);
+crc8_preset!(maxim, CRC_8_MAXIM);
+crc8_preset!(darc, CRC_8_DARC);
+crc8_preset!(rohc, CRC_8_ROHC);
+crc8_preset!(wcdma, CRC_8_WCDMA);
+
+const CHECK: &[u8] = b"123456789";
+
+#[test]
+fn check_values() {
+    assert_eq!(smbus(CHECK), 0xF4);
+    assert_eq!(maxim(CHECK), 0xA1);
+    assert_eq!(darc(CHECK), 0x15);
+    assert_eq!(rohc(CHECK), 0xD0);
+    assert_eq!(wcdma(CHECK), 0x25);
+}
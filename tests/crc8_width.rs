@@ -0,0 +1,16 @@
+use embedded_crc_macros::crc8_width;
+
+crc8_width!(crc7_mmc, 7, 0x09, 0, "CRC-7/MMC");
+crc8_width!(crc5_usb, 5, 0x05, 0x1F, true, true, 0x1F, "CRC-5/USB");
+crc8_width!(crc5_g704, 5, 0x15, 0, true, true, 0, "CRC-5/G-704");
+crc8_width!(crc3_gsm, 3, 0x03, 0, false, false, 0x07, "CRC-3/GSM");
+
+const CHECK: &[u8] = b"123456789";
+
+#[test]
+fn check_values() {
+    assert_eq!(crc7_mmc(CHECK), 0x75);
+    assert_eq!(crc5_usb(CHECK), 0x19);
+    assert_eq!(crc5_g704(CHECK), 0x07);
+    assert_eq!(crc3_gsm(CHECK), 0x4);
+}
@@ -0,0 +1,15 @@
+use core::hash::Hasher;
+use embedded_crc_macros::crc32_hasher;
+
+// CRC-32/ISO-HDLC: init 0xFFFF_FFFF, refout + xorout 0xFFFF_FFFF, so the
+// finalized checksum of an empty message is 0, while the raw register is the
+// initial value. This pins `Display`/`Debug` to the running register.
+crc32_hasher!(IsoHdlc, 0x04C1_1DB7, 0xFFFF_FFFF, true, true, 0xFFFF_FFFF, "CRC-32/ISO-HDLC");
+
+#[test]
+fn display_shows_running_register() {
+    let hasher = IsoHdlc::new();
+    assert_eq!(hasher.finish(), 0);
+    assert_eq!(format!("{}", hasher), "0xFFFFFFFF");
+    assert_eq!(format!("{:?}", hasher), "0xFFFFFFFF");
+}
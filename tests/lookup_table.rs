@@ -0,0 +1,9 @@
+mod base;
+use base::SMBUS_PEC_LOOKUP_TABLE;
+use embedded_crc_macros::generate_crc8_lookup_table;
+
+#[test]
+fn const_table_matches_build_rs_output() {
+    const LOOKUP_TABLE: [u8; 256] = generate_crc8_lookup_table(7);
+    assert_eq!(LOOKUP_TABLE, SMBUS_PEC_LOOKUP_TABLE);
+}
@@ -0,0 +1,21 @@
+use core::hash::Hasher;
+use embedded_crc_macros::{crc16_hasher, crc32_hasher};
+
+crc16_hasher!(CcittFalse, 0x1021, 0xFFFF, "CRC-16/CCITT-FALSE");
+crc32_hasher!(IsoHdlc, 0x04C1_1DB7, 0xFFFF_FFFF, true, true, 0xFFFF_FFFF, "CRC-32/ISO-HDLC");
+
+const CHECK: &[u8] = b"123456789";
+
+#[test]
+fn check_crc16() {
+    let mut hasher = CcittFalse::new();
+    hasher.write(CHECK);
+    assert_eq!(hasher.finish(), 0x29B1);
+}
+
+#[test]
+fn check_crc32() {
+    let mut hasher = IsoHdlc::new();
+    hasher.write(CHECK);
+    assert_eq!(hasher.finish(), 0xCBF4_3926);
+}
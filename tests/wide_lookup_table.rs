@@ -0,0 +1,32 @@
+use embedded_crc_macros::{
+    crc16_lookup_table, crc32_lookup_table, generate_crc16_lookup_table,
+    generate_crc32_lookup_table,
+};
+
+const CHECK: &[u8] = b"123456789";
+
+mod crc16 {
+    use super::*;
+    const LOOKUP_TABLE: [u16; 256] = generate_crc16_lookup_table(0x1021);
+
+    crc16_lookup_table!(ccitt_false, 0xFFFF, "CRC-16/CCITT-FALSE");
+    crc16_lookup_table!(kermit, 0x0000, true, true, 0x0000, "CRC-16/KERMIT");
+
+    #[test]
+    fn check_values() {
+        assert_eq!(ccitt_false(CHECK), 0x29B1);
+        assert_eq!(kermit(CHECK), 0x2189);
+    }
+}
+
+mod crc32 {
+    use super::*;
+    const LOOKUP_TABLE: [u32; 256] = generate_crc32_lookup_table(0x04C1_1DB7);
+
+    crc32_lookup_table!(iso_hdlc, 0xFFFF_FFFF, true, true, 0xFFFF_FFFF, "CRC-32/ISO-HDLC");
+
+    #[test]
+    fn check_value() {
+        assert_eq!(iso_hdlc(CHECK), 0xCBF4_3926);
+    }
+}